@@ -8,10 +8,11 @@
 mod tests;
 
 use std::cmp::Ordering;
+use std::collections::TryReserveError;
 use std::hash::{Hash, Hasher};
-use std::iter::FromIterator;
-use std::ops::{Deref, DerefMut};
-use std::{mem, slice, vec};
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::{mem, ptr, slice, vec};
 
 /// A collection of zero, one or many elements.
 #[derive(Debug)]
@@ -22,6 +23,19 @@ pub enum Zom<T> {
 }
 
 impl<T> Zom<T> {
+    /// Creates an empty `Zom` with space for at least `capacity` elements
+    /// without reallocating.
+    ///
+    /// Since `Zom` never allocates for zero or one element, a `capacity` of
+    /// `0` or `1` simply returns `Zom::Zero`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity > 1 {
+            Zom::Many(Vec::with_capacity(capacity))
+        } else {
+            Zom::Zero
+        }
+    }
+
     /// Adds a new element to the collection.
     pub fn push(&mut self, val: T) {
         match *self {
@@ -59,6 +73,332 @@ impl<T> Zom<T> {
         }
     }
 
+    /// Inserts an element at position `index`, shifting all elements after
+    /// it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        match self.take() {
+            Zom::Zero => {
+                if index > 0 {
+                    insert_failed(index, 0);
+                }
+                *self = Zom::One(element);
+            }
+            Zom::One(one) => {
+                match index {
+                    0 => *self = Zom::Many(vec![element, one]),
+                    1 => *self = Zom::Many(vec![one, element]),
+                    _ => insert_failed(index, 1),
+                }
+            }
+            Zom::Many(mut many) => {
+                many.insert(index, element);
+                *self = Zom::Many(many);
+            }
+        }
+    }
+
+    /// Removes and returns the element at position `index`, shifting all
+    /// elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        match self.take() {
+            Zom::Zero => remove_failed(index, 0),
+            Zom::One(one) => {
+                if index != 0 {
+                    remove_failed(index, 1);
+                }
+                one
+            }
+            Zom::Many(mut many) => {
+                let val = many.remove(index);
+                *self = Zom::Many(many);
+                val
+            }
+        }
+    }
+
+    /// Removes an element from the collection and returns it, replacing it
+    /// with the last element.
+    ///
+    /// This does not preserve ordering, but is `O(1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        match self.take() {
+            Zom::Zero => swap_remove_failed(index, 0),
+            Zom::One(one) => {
+                if index != 0 {
+                    swap_remove_failed(index, 1);
+                }
+                one
+            }
+            Zom::Many(mut many) => {
+                let val = many.swap_remove(index);
+                *self = Zom::Many(many);
+                val
+            }
+        }
+    }
+
+    /// Removes the specified range from the `Zom`, returning the removed
+    /// elements as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// remaining elements in the range are removed anyway, and the `Zom` is
+    /// left in its most compact representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the `Zom`.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let (start, end) = to_range(range, len);
+
+        // SAFETY: only used after the `vec::Drain` borrowing from `*self`
+        // (if any) has been dropped, so no aliasing mutable access occurs.
+        let zom: *mut Zom<T> = &mut *self;
+
+        let inner = match self.take() {
+            Zom::Zero => DrainInner::Zero,
+            Zom::One(one) => {
+                if start == 0 && end == 1 {
+                    DrainInner::One(Some(one))
+                } else {
+                    *self = Zom::One(one);
+                    DrainInner::One(None)
+                }
+            }
+            Zom::Many(many) => {
+                *self = Zom::Many(many);
+                match self {
+                    Zom::Many(many) => DrainInner::Many(many.drain(start..end)),
+                    _ => unreachable!(),
+                }
+            }
+        };
+
+        Drain { zom, inner }
+    }
+
+    /// Collapses a `Zom::Many` with zero or one elements to the matching
+    /// compact variant, without touching the capacity of a larger `Vec`.
+    fn collapse(&mut self) {
+        if let Zom::Many(many) = self {
+            match many.len() {
+                0 => *self = Zom::Zero,
+                1 => *self = Zom::One(many.pop().unwrap()),
+                _ => (),
+            }
+        }
+    }
+
+    /// Retains only the elements for which `f` returns `true`, removing the
+    /// rest and preserving the order of the retained elements.
+    ///
+    /// If `f` panics, the elements not yet visited are kept and no element
+    /// is dropped twice.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Like [`retain`], but the predicate is given a mutable reference to
+    /// each element.
+    ///
+    /// [`retain`]: enum.Zom.html#method.retain
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        match self {
+            Zom::Zero => (),
+            Zom::One(one) => {
+                if !f(one) {
+                    *self = Zom::Zero;
+                }
+            }
+            Zom::Many(many) => {
+                let original_len = many.len();
+                // Avoid double drop if a panic occurs while visiting the
+                // elements below; the guard restores a valid length on
+                // unwind as well as on a successful pass.
+                unsafe { many.set_len(0) };
+
+                struct BackshiftOnDrop<'a, T> {
+                    v: &'a mut Vec<T>,
+                    processed_len: usize,
+                    deleted_cnt: usize,
+                    original_len: usize,
+                }
+
+                impl<'a, T> Drop for BackshiftOnDrop<'a, T> {
+                    fn drop(&mut self) {
+                        if self.deleted_cnt > 0 {
+                            unsafe {
+                                ptr::copy(
+                                    self.v.as_ptr().add(self.processed_len),
+                                    self.v.as_mut_ptr().add(self.processed_len - self.deleted_cnt),
+                                    self.original_len - self.processed_len,
+                                );
+                            }
+                        }
+                        unsafe {
+                            self.v.set_len(self.original_len - self.deleted_cnt);
+                        }
+                    }
+                }
+
+                let mut g = BackshiftOnDrop {
+                    v: many,
+                    processed_len: 0,
+                    deleted_cnt: 0,
+                    original_len,
+                };
+
+                fn process_loop<F, T, const DELETED: bool>(
+                    original_len: usize,
+                    f: &mut F,
+                    g: &mut BackshiftOnDrop<'_, T>,
+                ) where
+                    F: FnMut(&mut T) -> bool,
+                {
+                    while g.processed_len != original_len {
+                        let cur = unsafe { &mut *g.v.as_mut_ptr().add(g.processed_len) };
+                        if !f(cur) {
+                            g.processed_len += 1;
+                            g.deleted_cnt += 1;
+                            unsafe { ptr::drop_in_place(cur) };
+                            if DELETED {
+                                continue;
+                            } else {
+                                break;
+                            }
+                        }
+                        if DELETED {
+                            unsafe {
+                                let hole_slot = g.v.as_mut_ptr().add(g.processed_len - g.deleted_cnt);
+                                ptr::copy_nonoverlapping(cur, hole_slot, 1);
+                            }
+                        }
+                        g.processed_len += 1;
+                    }
+                }
+
+                process_loop::<F, T, false>(original_len, &mut f, &mut g);
+                process_loop::<F, T, true>(original_len, &mut f, &mut g);
+                drop(g);
+            }
+        }
+
+        self.collapse();
+    }
+
+    /// Removes consecutive repeated elements, keeping only the first of
+    /// each run, the same way [`Vec::dedup`] does.
+    ///
+    /// [`Vec::dedup`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Like [`dedup`], but uses a key extracted by `key` to compare
+    /// elements for equality.
+    ///
+    /// [`dedup`]: enum.Zom.html#method.dedup
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Like [`dedup`], but merges elements using a custom equality
+    /// predicate instead of `PartialEq`.
+    ///
+    /// If `same_bucket` panics, the elements not yet visited are kept and
+    /// no element is dropped twice.
+    ///
+    /// [`dedup`]: enum.Zom.html#method.dedup
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if let Zom::Many(many) = self {
+            let len = many.len();
+            if len > 1 {
+                // Avoid double drop if `same_bucket` panics midway; the
+                // guard fills the gap between the read and write cursors
+                // on both the successful and the unwinding path.
+                struct FillGapOnDrop<'a, T> {
+                    read: usize,
+                    write: usize,
+                    vec: &'a mut Vec<T>,
+                }
+
+                impl<'a, T> Drop for FillGapOnDrop<'a, T> {
+                    fn drop(&mut self) {
+                        unsafe {
+                            let ptr = self.vec.as_mut_ptr();
+                            let len = self.vec.len();
+                            if self.read != self.write {
+                                let src = ptr.add(self.read);
+                                let dst = ptr.add(self.write);
+                                ptr::copy(src, dst, len - self.read);
+                            }
+                            self.vec.set_len(self.write + len - self.read);
+                        }
+                    }
+                }
+
+                let mut gap = FillGapOnDrop {
+                    read: 1,
+                    write: 1,
+                    vec: many,
+                };
+                let ptr = gap.vec.as_mut_ptr();
+
+                unsafe {
+                    while gap.read < len {
+                        let read_ptr = ptr.add(gap.read);
+                        let prev_ptr = ptr.add(gap.write - 1);
+
+                        if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                            gap.read += 1;
+                            ptr::drop_in_place(read_ptr);
+                        } else {
+                            let write_ptr = ptr.add(gap.write);
+                            ptr::copy_nonoverlapping(read_ptr, write_ptr, 1);
+                            gap.write += 1;
+                            gap.read += 1;
+                        }
+                    }
+                    drop(gap);
+                }
+            }
+        }
+
+        self.collapse();
+    }
+
     /// Removes all elements from the `Zom`, without deallocating any memory.
     pub fn clear(&mut self) {
         match self {
@@ -68,6 +408,38 @@ impl<T> Zom<T> {
         }
     }
 
+    /// Returns the number of elements the `Zom` can hold without
+    /// reallocating. Always `0` for `Zom::Zero` and `Zom::One`, since
+    /// neither allocates.
+    pub fn capacity(&self) -> usize {
+        match self {
+            Zom::Many(many) => many.capacity(),
+            Zom::Zero | Zom::One(_) => 0,
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements, promoting
+    /// the `Zom` to `Zom::Many` and reserving on the inner `Vec`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.to_vec().reserve(additional);
+    }
+
+    /// Like [`reserve`], but does not deliberately over-allocate to
+    /// speculatively avoid frequent reallocations.
+    ///
+    /// [`reserve`]: enum.Zom.html#method.reserve
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.to_vec().reserve_exact(additional);
+    }
+
+    /// Like [`reserve`], but returns a [`TryReserveError`] instead of
+    /// aborting if the allocation fails.
+    ///
+    /// [`reserve`]: enum.Zom.html#method.reserve
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.to_vec().try_reserve(additional)
+    }
+
     /// Minimizes the memory allocated by the `Zom`. If it is `Zom::Many` but
     /// only contains zero or one elements, it is converted to the appropriate
     /// variant.
@@ -218,6 +590,109 @@ impl<T> AsMut<[T]> for Zom<T> {
     }
 }
 
+/// A draining iterator for `Zom<T>`, created by [`Zom::drain`].
+///
+/// [`Zom::drain`]: enum.Zom.html#method.drain
+#[derive(Debug)]
+pub struct Drain<'a, T> {
+    zom: *mut Zom<T>,
+    inner: DrainInner<'a, T>,
+}
+
+#[derive(Debug)]
+enum DrainInner<'a, T> {
+    Zero,
+    One(Option<T>),
+    Many(vec::Drain<'a, T>),
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match &mut self.inner {
+            DrainInner::Zero => None,
+            DrainInner::One(one) => one.take(),
+            DrainInner::Many(many) => many.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match &mut self.inner {
+            DrainInner::Zero => None,
+            DrainInner::One(one) => one.take(),
+            DrainInner::Many(many) => many.next_back(),
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        match &self.inner {
+            DrainInner::Zero => 0,
+            DrainInner::One(one) => one.is_some() as usize,
+            DrainInner::Many(many) => many.len(),
+        }
+    }
+}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        match mem::replace(&mut self.inner, DrainInner::Zero) {
+            DrainInner::Zero | DrainInner::One(None) => (),
+            DrainInner::One(Some(one)) => drop(one),
+            DrainInner::Many(many) => {
+                // Dropping `many` runs `vec::Drain`'s own backshift of the
+                // tail elements before we touch the `Zom` again.
+                drop(many);
+                // SAFETY: the `vec::Drain` borrowing from `*zom` has just
+                // been dropped, so this is the only live access to `*zom`.
+                unsafe { (*self.zom).collapse() };
+            }
+        }
+    }
+}
+
+/// Converts a range bounds pair into a validated `[start, end)` index range,
+/// matching the panics of slice indexing.
+fn to_range<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!("slice index starts at {} but ends at {}", start, end);
+    }
+    if end > len {
+        panic!("range end index {} out of range for slice of length {}", end, len);
+    }
+    (start, end)
+}
+
 /// The result of calling [`Zom::into_iter`].
 ///
 /// [`Zom::into_iter`]: enum.IntoIter.html#method.into_iter
@@ -276,6 +751,21 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match mem::replace(&mut self.inner, IntoIterInner::Zero) {
+            IntoIterInner::Zero => None,
+            IntoIterInner::One(one) => Some(one),
+            IntoIterInner::Many(mut many) => {
+                let next = many.next_back();
+                self.inner = IntoIterInner::Many(many);
+                next
+            }
+        }
+    }
+}
+
 impl<T> ExactSizeIterator for IntoIter<T> {
     #[inline]
     fn len(&self) -> usize {
@@ -283,6 +773,8 @@ impl<T> ExactSizeIterator for IntoIter<T> {
     }
 }
 
+impl<T> FusedIterator for IntoIter<T> {}
+
 impl<T> IntoIterator for Zom<T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
@@ -379,6 +871,24 @@ impl<T> From<Vec<T>> for Zom<T> {
     }
 }
 
+#[cold]
+#[inline(never)]
+fn insert_failed(index: usize, len: usize) -> ! {
+    panic!("insertion index (is {}) should be <= len (is {})", index, len);
+}
+
+#[cold]
+#[inline(never)]
+fn remove_failed(index: usize, len: usize) -> ! {
+    panic!("removal index (is {}) should be < len (is {})", index, len);
+}
+
+#[cold]
+#[inline(never)]
+fn swap_remove_failed(index: usize, len: usize) -> ! {
+    panic!("swap_remove index (is {}) should be < len (is {})", index, len);
+}
+
 // TODO: replace this with slice::from_ref when it is stable.
 fn slice_from_ref<T>(s: &T) -> &[T] {
     unsafe { slice::from_raw_parts(s, 1) }
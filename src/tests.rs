@@ -58,3 +58,211 @@ fn iter() {
     let zom2: Zom<i32> = zom.iter().cloned().collect();
     assert_eq!(zom, zom2);
 }
+
+#[test]
+fn insert_remove_swap_remove() {
+    let mut zom = Zero;
+    zom.insert(0, 0);
+    assert_eq!(zom, One(0));
+    zom.insert(0, 1);
+    assert_eq!(zom, Many(vec![1, 0]));
+    zom.insert(2, 2);
+    assert_eq!(zom, Many(vec![1, 0, 2]));
+    zom.insert(1, 3);
+    assert_eq!(zom, Many(vec![1, 3, 0, 2]));
+
+    assert_eq!(zom.remove(1), 3);
+    assert_eq!(zom, Many(vec![1, 0, 2]));
+    assert_eq!(zom.swap_remove(0), 1);
+    assert_eq!(zom, Many(vec![2, 0]));
+    assert_eq!(zom.remove(1), 0);
+    assert_eq!(zom, Many(vec![2]));
+    assert_eq!(zom.remove(0), 2);
+    assert_eq!(zom, Many(vec![]));
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 1) should be <= len (is 0)")]
+fn insert_out_of_bounds_zero() {
+    let mut zom: Zom<i32> = Zero;
+    zom.insert(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn insert_out_of_bounds_one() {
+    let mut zom = One(0);
+    zom.insert(2, 1);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 0) should be < len (is 0)")]
+fn remove_out_of_bounds_zero() {
+    let mut zom: Zom<i32> = Zero;
+    zom.remove(0);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+fn remove_out_of_bounds_one() {
+    let mut zom = One(0);
+    zom.remove(1);
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index (is 0) should be < len (is 0)")]
+fn swap_remove_out_of_bounds_zero() {
+    let mut zom: Zom<i32> = Zero;
+    zom.swap_remove(0);
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index (is 1) should be < len (is 1)")]
+fn swap_remove_out_of_bounds_one() {
+    let mut zom = One(0);
+    zom.swap_remove(1);
+}
+
+#[test]
+fn drain_zero_and_one() {
+    let mut zom: Zom<i32> = Zero;
+    assert_eq!(zom.drain(0..0).collect::<Vec<_>>(), vec![]);
+    assert_eq!(zom, Zero);
+
+    let mut zom = One(0);
+    assert_eq!(zom.drain(0..0).collect::<Vec<_>>(), vec![]);
+    assert_eq!(zom, One(0));
+
+    let mut zom = One(0);
+    assert_eq!(zom.drain(0..1).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(zom, Zero);
+}
+
+#[test]
+fn drain_many() {
+    let mut zom = Many(vec![0, 1, 2, 3, 4]);
+    assert_eq!(zom.drain(1..3).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(zom, Many(vec![0, 3, 4]));
+
+    let mut zom = Many(vec![0, 1, 2]);
+    assert_eq!(zom.drain(1..).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![0, 1]);
+    assert_eq!(zom.drain(..).rev().collect::<Vec<_>>(), vec![1, 0]);
+    assert_eq!(zom, Zero);
+}
+
+#[test]
+fn drain_drop_without_consuming() {
+    let mut zom = Many(vec![0, 1, 2, 3]);
+    zom.drain(1..3);
+    assert_eq!(zom, Many(vec![0, 3]));
+}
+
+#[test]
+fn retain() {
+    let mut zom: Zom<i32> = Zero;
+    zom.retain(|_| false);
+    assert_eq!(zom, Zero);
+
+    let mut zom = One(0);
+    zom.retain(|&x| x != 0);
+    assert_eq!(zom, Zero);
+
+    let mut zom = One(0);
+    zom.retain(|&x| x == 0);
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![0, 1, 2, 3, 4]);
+    zom.retain(|&x| x % 2 == 0);
+    assert_eq!(zom, Many(vec![0, 2, 4]));
+
+    let mut zom = Many(vec![0, 1, 2]);
+    zom.retain(|&x| x == 0);
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![0, 1, 2]);
+    zom.retain(|_| false);
+    assert_eq!(zom, Zero);
+}
+
+#[test]
+fn retain_mut() {
+    let mut zom = Many(vec![0, 1, 2, 3, 4]);
+    zom.retain_mut(|x| {
+        *x *= 2;
+        *x < 6
+    });
+    assert_eq!(zom, Many(vec![0, 2, 4]));
+}
+
+#[test]
+fn capacity() {
+    let zom: Zom<i32> = Zom::with_capacity(0);
+    assert_eq!(zom, Zero);
+    assert_eq!(zom.capacity(), 0);
+
+    let zom: Zom<i32> = Zom::with_capacity(1);
+    assert_eq!(zom, Zero);
+    assert_eq!(zom.capacity(), 0);
+
+    let zom: Zom<i32> = Zom::with_capacity(4);
+    assert_eq!(zom, Many(vec![]));
+    assert!(zom.capacity() >= 4);
+
+    let mut zom = One(0);
+    assert_eq!(zom.capacity(), 0);
+    zom.reserve(4);
+    assert!(zom.capacity() >= 4);
+    assert_eq!(zom, Many(vec![0]));
+
+    let mut zom: Zom<i32> = Zero;
+    assert!(zom.try_reserve(4).is_ok());
+    assert!(zom.capacity() >= 4);
+}
+
+#[test]
+fn into_iter_double_ended() {
+    let zom: Zom<i32> = Zero;
+    assert_eq!(zom.into_iter().rev().collect::<Vec<_>>(), vec![]);
+
+    let zom = One(0);
+    assert_eq!(zom.into_iter().rev().collect::<Vec<_>>(), vec![0]);
+
+    let zom = Many(vec![0, 1, 2, 3]);
+    let mut iter = zom.into_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn dedup() {
+    let mut zom: Zom<i32> = Zero;
+    zom.dedup();
+    assert_eq!(zom, Zero);
+
+    let mut zom = One(0);
+    zom.dedup();
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![0, 0, 1, 1, 1, 2, 0, 0]);
+    zom.dedup();
+    assert_eq!(zom, Many(vec![0, 1, 2, 0]));
+
+    let mut zom = Many(vec![0, 0, 0]);
+    zom.dedup();
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![0, 1]);
+    zom.dedup_by_key(|_| 0);
+    assert_eq!(zom, One(0));
+
+    let mut zom = Many(vec![1i32, -1, 2, -2, -2]);
+    zom.dedup_by(|a, b| a.abs() == b.abs());
+    assert_eq!(zom, Many(vec![1, 2]));
+}